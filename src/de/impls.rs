@@ -0,0 +1,273 @@
+use crate::de::{Deserialize, Seq, Visitor};
+use crate::error::{Error, Result};
+use crate::json::Number;
+#[cfg(feature = "arbitrary_precision")]
+use alloc::string::ToString;
+use alloc::boxed::Box;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+
+/// A `[MaybeUninit<T>; N]` together with a count of how many leading slots
+/// have actually been initialized, so that bailing out partway through
+/// (an element errors, or the sequence ends too early) drops exactly the
+/// slots that were written and nothing else.
+struct PartialArray<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    count: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    fn new() -> Self {
+        PartialArray {
+            // SAFETY: `MaybeUninit<T>` does not require initialization, so
+            // an array of them is always valid, uninitialized or not.
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+            count: 0,
+        }
+    }
+
+    fn push(&mut self, value: T) -> Result<()> {
+        if self.count == N {
+            // The sequence produced more than N elements.
+            return Err(Error);
+        }
+        self.data[self.count] = MaybeUninit::new(value);
+        self.count += 1;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<[T; N]> {
+        if self.count != N {
+            // The sequence ended before filling all N slots.
+            return Err(Error);
+        }
+        // Every slot is now initialized. Hand ownership of them to the
+        // caller without running our Drop impl over them again.
+        self.count = 0;
+        // SAFETY: `transmute_copy` sidesteps the compiler's inability to
+        // confirm that `[MaybeUninit<T>; N]` and `[T; N]` have the same
+        // size when `N` is a const generic parameter; they always do.
+        Ok(unsafe { mem::transmute_copy(&self.data) })
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        for slot in &mut self.data[..self.count] {
+            // SAFETY: the first `count` slots were initialized by `push`
+            // and have not been moved out of.
+            unsafe { ptr::drop_in_place(slot.as_mut_ptr()) };
+        }
+    }
+}
+
+struct ArrayBuilder<'a, T, const N: usize> {
+    out: &'a mut Option<[T; N]>,
+    array: PartialArray<T, N>,
+    element: Option<T>,
+}
+
+impl<'a, T, const N: usize> Seq for ArrayBuilder<'a, T, N>
+where
+    T: Deserialize,
+{
+    fn element(&mut self) -> Result<&mut dyn Visitor> {
+        if let Some(value) = self.element.take() {
+            self.array.push(value)?;
+        }
+        Ok(Deserialize::begin(&mut self.element))
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if let Some(value) = self.element.take() {
+            self.array.push(value)?;
+        }
+        let array = mem::replace(&mut self.array, PartialArray::new()).finish()?;
+        *self.out = Some(array);
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> Deserialize for [T; N]
+where
+    T: Deserialize,
+{
+    fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+        impl<T, const N: usize> Visitor for Option<[T; N]>
+        where
+            T: Deserialize,
+        {
+            fn seq(&mut self) -> Result<Box<dyn Seq + '_>> {
+                Ok(Box::new(ArrayBuilder {
+                    out: self,
+                    array: PartialArray::new(),
+                    element: None,
+                }))
+            }
+        }
+
+        out
+    }
+}
+
+impl Deserialize for f64 {
+    fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+        impl Visitor for Option<f64> {
+            fn negative(&mut self, n: i64) -> Result<()> {
+                *self = Some(n as f64);
+                Ok(())
+            }
+
+            fn nonnegative(&mut self, n: u64) -> Result<()> {
+                *self = Some(n as f64);
+                Ok(())
+            }
+
+            fn float(&mut self, n: f64) -> Result<()> {
+                *self = Some(n);
+                Ok(())
+            }
+        }
+        out
+    }
+}
+
+impl Deserialize for Number {
+    fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+        impl Visitor for Option<Number> {
+            fn nonnegative(&mut self, n: u64) -> Result<()> {
+                *self = Some(Number::U64(n));
+                Ok(())
+            }
+
+            fn negative(&mut self, n: i64) -> Result<()> {
+                *self = Some(Number::I64(n));
+                Ok(())
+            }
+
+            fn float(&mut self, n: f64) -> Result<()> {
+                *self = Some(Number::F64(n));
+                Ok(())
+            }
+
+            // The scanner calls this instead of `nonnegative`/`negative`/
+            // `float` when a numeric token overflows all three, so the
+            // original digits are preserved rather than truncated or
+            // rejected.
+            #[cfg(feature = "arbitrary_precision")]
+            fn raw_number(&mut self, n: &str) -> Result<()> {
+                *self = Some(Number::Unbounded(n.to_string()));
+                Ok(())
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct DropCounter(u64);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl Deserialize for DropCounter {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            impl Visitor for Option<DropCounter> {
+                fn nonnegative(&mut self, n: u64) -> Result<()> {
+                    *self = Some(DropCounter(n));
+                    Ok(())
+                }
+            }
+            out
+        }
+    }
+
+    #[test]
+    fn array_deserializes_exact_length_sequence() {
+        let mut out: Option<[DropCounter; 2]> = None;
+        {
+            let mut seq = Deserialize::begin(&mut out).seq().unwrap();
+            seq.element().unwrap().nonnegative(10).unwrap();
+            seq.element().unwrap().nonnegative(20).unwrap();
+            seq.finish().unwrap();
+        }
+        let array = out.unwrap();
+        assert_eq!(array[0].0, 10);
+        assert_eq!(array[1].0, 20);
+    }
+
+    #[test]
+    fn array_errors_when_sequence_ends_early() {
+        let mut out: Option<[DropCounter; 2]> = None;
+        {
+            let mut seq = Deserialize::begin(&mut out).seq().unwrap();
+            seq.element().unwrap().nonnegative(1).unwrap();
+            assert!(seq.finish().is_err());
+        }
+        assert!(out.is_none());
+    }
+
+    #[test]
+    fn array_errors_when_sequence_has_too_many_elements() {
+        let mut out: Option<[DropCounter; 2]> = None;
+        let mut seq = Deserialize::begin(&mut out).seq().unwrap();
+        seq.element().unwrap().nonnegative(1).unwrap();
+        seq.element().unwrap().nonnegative(2).unwrap();
+        seq.element().unwrap().nonnegative(3).unwrap();
+        assert!(seq.element().is_err());
+    }
+
+    #[test]
+    fn array_drops_exactly_the_initialized_elements_on_early_exit() {
+        let before = DROPS.load(Ordering::SeqCst);
+        {
+            let mut out: Option<[DropCounter; 3]> = None;
+            let mut seq = Deserialize::begin(&mut out).seq().unwrap();
+            seq.element().unwrap().nonnegative(1).unwrap();
+            seq.element().unwrap().nonnegative(2).unwrap();
+            seq.element().unwrap().nonnegative(3).unwrap();
+            // `seq`, holding two committed elements plus one pending in
+            // `element`, is dropped here without ever calling `finish`.
+        }
+        assert_eq!(DROPS.load(Ordering::SeqCst) - before, 3);
+    }
+
+    #[test]
+    fn number_deserializes_each_primitive_shape() {
+        let mut out = None;
+        Deserialize::begin(&mut out).nonnegative(7).unwrap();
+        assert_eq!(out, Some(Number::U64(7)));
+
+        let mut out = None;
+        Deserialize::begin(&mut out).negative(-7).unwrap();
+        assert_eq!(out, Some(Number::I64(-7)));
+
+        let mut out = None;
+        Deserialize::begin(&mut out).float(1.5).unwrap();
+        assert_eq!(out, Some(Number::F64(1.5)));
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn number_falls_back_to_raw_token_on_overflow() {
+        let mut out = None;
+        Deserialize::begin(&mut out)
+            .raw_number("123456789012345678901234567890")
+            .unwrap();
+        assert_eq!(
+            out,
+            Some(Number::Unbounded(
+                "123456789012345678901234567890".to_string()
+            ))
+        );
+    }
+}
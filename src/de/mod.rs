@@ -23,6 +23,17 @@
 //! The Visitor trait has a method corresponding to each supported primitive
 //! type.
 //!
+//! ## Deserializing an enum
+//!
+//! Externally-tagged enums have no dedicated trait method; they are modeled
+//! in terms of the primitives above. A unit variant deserializes through
+//! [`Visitor::string`], matching the bare string against the variant name.
+//! A newtype or struct variant deserializes through [`Visitor::map`] as a
+//! single-key map, where [`Map::key`] selects the variant by name and hands
+//! back the place for its payload. [`variant_index`] is provided so
+//! generated code for both shapes can share one implementation of "does
+//! this name match one of my variants" rather than hand-rolling the match
+//! arms per enum.
 
 mod impls;
 
@@ -79,6 +90,25 @@ pub trait Visitor {
         Err(Error)
     }
 
+    /// Capture the original digits of a number that overflowed every
+    /// primitive representation ([`Visitor::nonnegative`],
+    /// [`Visitor::negative`], [`Visitor::float`]). Only ever called under
+    /// the `arbitrary_precision` feature; the default implementation
+    /// rejects the input, matching the behavior without that feature.
+    fn raw_number(&mut self, n: &str) -> Result<()> {
+        let _ = n;
+        Err(Error)
+    }
+
+    /// Recognize an RFC 3339 datetime given as the unquoted token `s`
+    /// (e.g. TOML's bare `1979-05-27T07:32:00Z` syntax). Formats that have
+    /// no distinct datetime lexical form, such as plain JSON, never call
+    /// this and instead hand the token to [`Visitor::string`].
+    fn datetime(&mut self, s: &str) -> Result<()> {
+        let _ = s;
+        Err(Error)
+    }
+
     fn seq(&mut self) -> Result<Box<dyn Seq + '_>> {
         Err(Error)
     }
@@ -103,3 +133,139 @@ pub trait Map {
     fn key(&mut self, k: &str) -> Result<&mut dyn Visitor>;
     fn finish(&mut self) -> Result<()>;
 }
+
+/// Not public API. Shared by generated `Deserialize` impls for externally
+/// tagged enums to resolve a variant's externally-tagged name — received
+/// either as a bare string (unit variants) or as the single key of a map
+/// (newtype and struct variants) — to its index among `variants`.
+///
+/// [Refer to the module documentation for how enums are deserialized.][crate::de]
+#[doc(hidden)]
+pub fn variant_index(name: &str, variants: &[&str]) -> Result<usize> {
+    variants.iter().position(|&variant| variant == name).ok_or(Error)
+}
+
+/// Not public API. The place used by a generated `Deserialize` impl for a
+/// fieldless (unit) enum variant: the externally-tagged encoding is a bare
+/// string equal to the variant's name, so this just forwards
+/// [`Visitor::string`] through [`variant_index`].
+#[doc(hidden)]
+pub struct UnitVariant<'a> {
+    pub out: &'a mut Option<usize>,
+    pub variants: &'static [&'static str],
+}
+
+impl<'a> Visitor for UnitVariant<'a> {
+    fn string(&mut self, s: &str) -> Result<()> {
+        *self.out = Some(variant_index(s, self.variants)?);
+        Ok(())
+    }
+}
+
+/// Newtype and struct variants cannot share a single generic `Map` helper
+/// the way unit variants share [`UnitVariant`]: each variant of a given
+/// enum has its own payload type, so the single-key map's value place
+/// differs arm to arm. A generated `Deserialize` impl instead defines its
+/// own small `Map` type whose `key` method resolves the externally-tagged
+/// name via [`variant_index`] and then matches on the resulting index to
+/// hand back that one variant's place, same as the example in
+/// `de::tests::newtype_variant_dispatch`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+
+    enum Shape {
+        Circle(f64),
+        Point,
+    }
+
+    impl Deserialize for Shape {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            impl Visitor for Option<Shape> {
+                fn string(&mut self, s: &str) -> Result<()> {
+                    // Unit variant: a bare string naming the variant.
+                    let mut index = None;
+                    UnitVariant {
+                        out: &mut index,
+                        variants: &["Point"],
+                    }
+                    .string(s)?;
+                    *self = match index {
+                        Some(0) => Some(Shape::Point),
+                        _ => return Err(Error),
+                    };
+                    Ok(())
+                }
+
+                fn map(&mut self) -> Result<Box<dyn Map + '_>> {
+                    // Newtype variant: a single-key map naming the variant,
+                    // whose value is that variant's payload.
+                    struct ShapeMap<'a> {
+                        out: &'a mut Option<Shape>,
+                        radius: Option<f64>,
+                        matched: bool,
+                    }
+
+                    impl<'a> Map for ShapeMap<'a> {
+                        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+                            match variant_index(k, &["Circle", "Point"])? {
+                                0 => {
+                                    self.matched = true;
+                                    Ok(Deserialize::begin(&mut self.radius))
+                                }
+                                _ => Err(Error),
+                            }
+                        }
+
+                        fn finish(&mut self) -> Result<()> {
+                            if self.matched {
+                                *self.out = Some(Shape::Circle(self.radius.ok_or(Error)?));
+                                Ok(())
+                            } else {
+                                Err(Error)
+                            }
+                        }
+                    }
+
+                    Ok(Box::new(ShapeMap {
+                        out: self,
+                        radius: None,
+                        matched: false,
+                    }))
+                }
+            }
+            out
+        }
+    }
+
+    #[test]
+    fn unit_variant_dispatch() {
+        let mut out = None;
+        Deserialize::begin(&mut out).string("Point").unwrap();
+        assert!(matches!(out, Some(Shape::Point)));
+    }
+
+    #[test]
+    fn unit_variant_unknown_name_errors() {
+        let mut out: Option<Shape> = None;
+        assert!(Deserialize::begin(&mut out).string("Square").is_err());
+    }
+
+    #[test]
+    fn newtype_variant_dispatch() {
+        let mut out: Option<Shape> = None;
+        {
+            let mut map = Deserialize::begin(&mut out).map().unwrap();
+            map.key("Circle").unwrap().float(2.0).unwrap();
+            map.finish().unwrap();
+        }
+        assert!(matches!(out, Some(Shape::Circle(radius)) if radius == 2.0));
+    }
+
+    #[test]
+    fn variant_index_matches_by_name() {
+        assert_eq!(variant_index("b", &["a", "b", "c"]).unwrap(), 1);
+        assert!(variant_index("z", &["a", "b", "c"]).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use crate::de::{Deserialize, Visitor};
+use crate::error::{Error, Result};
+use crate::ser::{Fragment, Serialize};
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use core::fmt::{self, Display};
+
+/// The date component of a [`Datetime`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// The time-of-day component of a [`Datetime`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Time {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+}
+
+/// The UTC offset component of a [`Datetime`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Offset {
+    /// Zulu, i.e. UTC.
+    Z,
+    /// Offset from UTC in minutes, positive to the east.
+    Custom { minutes: i16 },
+}
+
+/// An RFC 3339 datetime, modeled on TOML's `Value::Datetime`.
+///
+/// Like [`Number`][crate::json::Number], a `Datetime` is a scalar rather
+/// than a container, so `Serialize` for it is trivially non-recursive: it just
+/// writes the formatted string. `Deserialize` accepts the same RFC 3339
+/// text whether it arrives quoted, via [`Visitor::string`] (the only shape
+/// plain JSON has), or bare, via [`Visitor::datetime`] (the shape a format
+/// like TOML would use). Adding a second, TOML-flavored serialization
+/// backend that actually emits the bare form is out of scope here; this
+/// type only covers the value model and the recognition hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Datetime {
+    pub date: Date,
+    pub time: Time,
+    pub offset: Offset,
+}
+
+impl Datetime {
+    /// Parse an RFC 3339 timestamp such as `1979-05-27T07:32:00Z` or
+    /// `1979-05-27T00:32:00-07:00`.
+    fn parse(s: &str) -> Result<Datetime> {
+        let bytes = s.as_bytes();
+
+        fn digit(bytes: &[u8], i: usize) -> Result<u32> {
+            match bytes.get(i) {
+                Some(b) if b.is_ascii_digit() => Ok((b - b'0') as u32),
+                _ => Err(Error),
+            }
+        }
+        fn two(bytes: &[u8], i: usize) -> Result<u32> {
+            Ok(digit(bytes, i)? * 10 + digit(bytes, i + 1)?)
+        }
+
+        if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(Error);
+        }
+        let year = (two(bytes, 0)? * 100 + two(bytes, 2)?) as u16;
+        let month = two(bytes, 5)? as u8;
+        let day = two(bytes, 8)? as u8;
+        if !matches!(bytes[10], b'T' | b't' | b' ') || bytes[13] != b':' || bytes[16] != b':' {
+            return Err(Error);
+        }
+        let hour = two(bytes, 11)? as u8;
+        let minute = two(bytes, 14)? as u8;
+        let second = two(bytes, 17)? as u8;
+
+        let mut index = 19;
+        let mut nanosecond = 0u32;
+        if bytes.get(index) == Some(&b'.') {
+            index += 1;
+            let start = index;
+            while bytes.get(index).is_some_and(u8::is_ascii_digit) {
+                index += 1;
+            }
+            if index == start {
+                return Err(Error);
+            }
+            // Only the first 9 fractional digits (nanosecond precision)
+            // are kept; RFC 3339 allows more, which are simply truncated.
+            for b in s[start..index].bytes().take(9) {
+                nanosecond = nanosecond * 10 + (b - b'0') as u32;
+            }
+            for _ in 0..9usize.saturating_sub(index - start) {
+                nanosecond *= 10;
+            }
+        }
+
+        let offset = match bytes.get(index) {
+            Some(b'Z') | Some(b'z') => {
+                index += 1;
+                Offset::Z
+            }
+            Some(b'+') | Some(b'-') => {
+                let negative = bytes[index] == b'-';
+                index += 1;
+                let offset_hour = two(bytes, index)?;
+                index += 2;
+                if bytes.get(index) != Some(&b':') {
+                    return Err(Error);
+                }
+                index += 1;
+                let offset_minute = two(bytes, index)?;
+                index += 2;
+                let minutes = (offset_hour * 60 + offset_minute) as i16;
+                Offset::Custom {
+                    minutes: if negative { -minutes } else { minutes },
+                }
+            }
+            _ => return Err(Error),
+        };
+
+        if index != bytes.len() {
+            return Err(Error);
+        }
+
+        Ok(Datetime {
+            date: Date { year, month, day },
+            time: Time {
+                hour,
+                minute,
+                second,
+                nanosecond,
+            },
+            offset,
+        })
+    }
+}
+
+impl Deserialize for Datetime {
+    fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+        impl Visitor for Option<Datetime> {
+            fn string(&mut self, s: &str) -> Result<()> {
+                *self = Some(Datetime::parse(s)?);
+                Ok(())
+            }
+
+            fn datetime(&mut self, s: &str) -> Result<()> {
+                *self = Some(Datetime::parse(s)?);
+                Ok(())
+            }
+        }
+        out
+    }
+}
+
+impl Display for Datetime {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let Date { year, month, day } = self.date;
+        let Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        } = self.time;
+        write!(
+            formatter,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year, month, day, hour, minute, second
+        )?;
+        if nanosecond > 0 {
+            write!(formatter, ".{:09}", nanosecond)?;
+        }
+        match self.offset {
+            Offset::Z => formatter.write_str("Z"),
+            Offset::Custom { minutes } => {
+                let sign = if minutes < 0 { '-' } else { '+' };
+                let minutes = minutes.unsigned_abs();
+                write!(formatter, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+            }
+        }
+    }
+}
+
+impl Serialize for Datetime {
+    fn begin(&self) -> Fragment {
+        Fragment::Str(Cow::Owned(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Option<Datetime> {
+        let mut out = None;
+        Deserialize::begin(&mut out).datetime(s).ok()?;
+        out
+    }
+
+    #[test]
+    fn round_trips_through_display_and_datetime_hook() {
+        let datetime = Datetime {
+            date: Date {
+                year: 1979,
+                month: 5,
+                day: 27,
+            },
+            time: Time {
+                hour: 7,
+                minute: 32,
+                second: 0,
+                nanosecond: 0,
+            },
+            offset: Offset::Z,
+        };
+        assert_eq!(parse(&datetime.to_string()).unwrap(), datetime);
+    }
+
+    #[test]
+    fn string_hook_parses_the_same_text_as_the_datetime_hook() {
+        let mut out: Option<Datetime> = None;
+        Deserialize::begin(&mut out)
+            .string("1979-05-27T00:32:00-07:00")
+            .unwrap();
+        assert_eq!(
+            out.unwrap().offset,
+            Offset::Custom { minutes: -7 * 60 }
+        );
+    }
+
+    #[test]
+    fn parses_fractional_seconds() {
+        let datetime = parse("1979-05-27T07:32:00.5Z").unwrap();
+        assert_eq!(datetime.time.nanosecond, 500_000_000);
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(parse("not a datetime").is_none());
+        assert!(parse("1979-05-27T07:32:00").is_none());
+    }
+}
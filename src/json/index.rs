@@ -0,0 +1,168 @@
+use crate::json::Value;
+use alloc::string::String;
+use core::ops;
+
+/// A type that can be used to index into a `json::Value`.
+///
+/// The [`get`][Value::get] and [`get_mut`][Value::get_mut] methods of
+/// `Value` accept any type that implements `Index`, as does the
+/// [square-bracket indexing operator][ops::Index] on `Value`. This trait
+/// is implemented for strings which are used as the index into a JSON
+/// object, and for `usize` which is used as the index into a JSON array.
+///
+/// This trait is sealed and cannot be implemented outside of miniserde.
+pub trait Index: private::Sealed {
+    #[doc(hidden)]
+    fn index_into<'v>(&self, v: &'v Value) -> Option<&'v Value>;
+    #[doc(hidden)]
+    fn index_into_mut<'v>(&self, v: &'v mut Value) -> Option<&'v mut Value>;
+}
+
+impl Index for usize {
+    fn index_into<'v>(&self, v: &'v Value) -> Option<&'v Value> {
+        match v {
+            Value::Array(array) => array.get(*self),
+            _ => None,
+        }
+    }
+
+    fn index_into_mut<'v>(&self, v: &'v mut Value) -> Option<&'v mut Value> {
+        match v {
+            Value::Array(array) => array.get_mut(*self),
+            _ => None,
+        }
+    }
+}
+
+impl Index for str {
+    fn index_into<'v>(&self, v: &'v Value) -> Option<&'v Value> {
+        match v {
+            Value::Object(object) => object.get(self),
+            _ => None,
+        }
+    }
+
+    fn index_into_mut<'v>(&self, v: &'v mut Value) -> Option<&'v mut Value> {
+        match v {
+            Value::Object(object) => object.get_mut(self),
+            _ => None,
+        }
+    }
+}
+
+impl Index for String {
+    fn index_into<'v>(&self, v: &'v Value) -> Option<&'v Value> {
+        self.as_str().index_into(v)
+    }
+
+    fn index_into_mut<'v>(&self, v: &'v mut Value) -> Option<&'v mut Value> {
+        self.as_str().index_into_mut(v)
+    }
+}
+
+impl<'a, T> Index for &'a T
+where
+    T: ?Sized + Index,
+{
+    fn index_into<'v>(&self, v: &'v Value) -> Option<&'v Value> {
+        (**self).index_into(v)
+    }
+
+    fn index_into_mut<'v>(&self, v: &'v mut Value) -> Option<&'v mut Value> {
+        (**self).index_into_mut(v)
+    }
+}
+
+mod private {
+    use alloc::string::String;
+
+    pub trait Sealed {}
+    impl Sealed for usize {}
+    impl Sealed for str {}
+    impl Sealed for String {}
+    impl<'a, T> Sealed for &'a T where T: ?Sized + Sealed {}
+}
+
+/// A static `Value::Null` handed out by `ops::Index` when the key or index
+/// is absent, so that chained indexing like `value["a"]["b"][0]` never
+/// panics on missing data.
+static NULL: Value = Value::Null;
+
+impl<I> ops::Index<I> for Value
+where
+    I: Index,
+{
+    type Output = Value;
+
+    /// Index into a `json::Value` using the syntax `value[0]` or
+    /// `value["k"]`.
+    ///
+    /// Returns `Value::Null` if the type of `self` does not match the type
+    /// of the index, or if the key or index is not present. This allows
+    /// chained indexing such as `value["a"]["b"][0]` to short-circuit to
+    /// `Null` rather than panicking on absent data.
+    fn index(&self, index: I) -> &Value {
+        index.index_into(self).unwrap_or(&NULL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{Array, Object};
+    use alloc::borrow::ToOwned;
+
+    fn object() -> Value {
+        let mut object = Object::new();
+        let mut inner = Object::new();
+        let mut array = Array::new();
+        array.push(Value::Bool(true));
+        inner.insert("b".to_owned(), Value::Array(array));
+        object.insert("a".to_owned(), Value::Object(inner));
+        Value::Object(object)
+    }
+
+    #[test]
+    fn missing_key_indexes_to_null() {
+        let value = object();
+        assert_eq!(value["missing"], Value::Null);
+        assert_eq!(value.get("missing"), None);
+    }
+
+    #[test]
+    fn out_of_range_index_indexes_to_null() {
+        let value = object();
+        assert_eq!(value["a"]["b"][99], Value::Null);
+        assert_eq!(value["a"]["b"].get(99), None);
+    }
+
+    #[test]
+    fn chained_indexing_through_an_absent_key_short_circuits_to_null() {
+        let value = object();
+        // "missing" isn't present, so everything chained after it should
+        // short-circuit to Null rather than panicking.
+        assert_eq!(value["missing"]["b"][0]["c"], Value::Null);
+    }
+
+    #[test]
+    fn indexing_matches_get() {
+        let value = object();
+        assert_eq!(value["a"]["b"][0], Value::Bool(true));
+        assert_eq!(value.get("a").and_then(|a| a.get("b")).and_then(|b| b.get(0)), Some(&Value::Bool(true)));
+    }
+
+    #[test]
+    fn get_mut_writes_through_to_the_same_value() {
+        let mut value = object();
+        if let Some(inner) = value.get_mut("a").and_then(|a| a.get_mut("b")).and_then(|b| b.get_mut(0)) {
+            *inner = Value::Bool(false);
+        }
+        assert_eq!(value["a"]["b"][0], Value::Bool(false));
+    }
+
+    #[test]
+    fn get_mut_on_missing_key_returns_none() {
+        let mut value = object();
+        assert!(value.get_mut("missing").is_none());
+    }
+}
@@ -1,13 +1,26 @@
 use crate::error::Result;
 use crate::ser::{Fragment, Serialize};
+#[cfg(feature = "arbitrary_precision")]
+use alloc::string::String;
+use core::convert::TryFrom;
 use core::fmt::{self, Display};
 
 /// A JSON number represented by some Rust primitive.
+///
+/// With the `arbitrary_precision` feature enabled, numbers that don't fit
+/// losslessly into `u64`, `i64`, or `f64` (128-bit ids, high-precision
+/// decimals, and the like) are preserved verbatim as the
+/// [`Unbounded`][Number::Unbounded] variant rather than being truncated or
+/// rejected.
 #[derive(Clone, Debug)]
 pub enum Number {
     U64(u64),
     I64(i64),
     F64(f64),
+    /// The original numeric token, preserved byte-for-byte because it
+    /// overflowed every primitive representation above.
+    #[cfg(feature = "arbitrary_precision")]
+    Unbounded(String),
 }
 
 impl Display for Number {
@@ -16,6 +29,36 @@ impl Display for Number {
             Number::U64(n) => formatter.write_str(itoa::Buffer::new().format(*n)),
             Number::I64(n) => formatter.write_str(itoa::Buffer::new().format(*n)),
             Number::F64(n) => formatter.write_str(ryu::Buffer::new().format(*n)),
+            #[cfg(feature = "arbitrary_precision")]
+            Number::Unbounded(token) => formatter.write_str(token),
+        }
+    }
+}
+
+impl PartialEq for Number {
+    /// Numbers compare equal when they represent the same mathematical
+    /// value, regardless of which primitive variant holds it, so
+    /// `Number::U64(5) == Number::F64(5.0)`.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Number::U64(a), Number::U64(b)) => a == b,
+            (Number::I64(a), Number::I64(b)) => a == b,
+            (Number::F64(a), Number::F64(b)) => a == b,
+            (Number::U64(a), Number::I64(b)) | (Number::I64(b), Number::U64(a)) => {
+                i64::try_from(*a) == Ok(*b)
+            }
+            // `as f64` rounds large integers to a nearby float, so the float
+            // is also converted back (within range) and compared again.
+            (Number::U64(a), Number::F64(b)) | (Number::F64(b), Number::U64(a)) => {
+                *a as f64 == *b && *b < 18446744073709551616.0 && *b as u64 == *a
+            }
+            (Number::I64(a), Number::F64(b)) | (Number::F64(b), Number::I64(a)) => {
+                *a as f64 == *b && *b < 9223372036854775808.0 && *b as i64 == *a
+            }
+            #[cfg(feature = "arbitrary_precision")]
+            (Number::Unbounded(a), Number::Unbounded(b)) => a == b,
+            #[cfg(feature = "arbitrary_precision")]
+            _ => false,
         }
     }
 }
@@ -26,6 +69,30 @@ impl Serialize for Number {
             Number::U64(n) => Fragment::U64(*n),
             Number::I64(n) => Fragment::I64(*n),
             Number::F64(n) => Fragment::F64(*n),
+            // Written uninterpreted rather than through `itoa`/`ryu` so the
+            // original digits round-trip exactly, including precision that
+            // none of the primitive variants above can hold.
+            #[cfg(feature = "arbitrary_precision")]
+            Number::Unbounded(token) => Fragment::Raw(token),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "arbitrary_precision"))]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn unbounded_round_trips_the_exact_digits() {
+        let token = "123456789012345678901234567890";
+        let number = Number::Unbounded(token.to_string());
+
+        assert_eq!(number.to_string(), token);
+        let fragment = Serialize::begin(&number);
+        match fragment {
+            Fragment::Raw(s) => assert_eq!(s, token),
+            _ => panic!("expected Fragment::Raw"),
         }
     }
 }
@@ -0,0 +1,137 @@
+use crate::error::Result;
+use crate::json::{drop, Value};
+use crate::private;
+use crate::ser::{Fragment, Serialize};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::fmt::{self, Debug};
+use core::mem::{self, ManuallyDrop};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+
+/// A `BTreeMap<String, Value>` with a non-recursive drop impl.
+#[derive(Default)]
+pub struct Object {
+    inner: BTreeMap<String, Value>,
+}
+
+impl Drop for Object {
+    fn drop(&mut self) {
+        mem::take(&mut self.inner)
+            .into_values()
+            .for_each(drop::safely);
+    }
+}
+
+fn take(object: Object) -> BTreeMap<String, Value> {
+    let object = ManuallyDrop::new(object);
+    unsafe { ptr::read(&object.inner) }
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Object {
+            inner: BTreeMap::new(),
+        }
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&Value>
+    where
+        String: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.inner.get(key)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut Value>
+    where
+        String: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.inner.get_mut(key)
+    }
+}
+
+impl Deref for Object {
+    type Target = BTreeMap<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for Object {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl Clone for Object {
+    fn clone(&self) -> Self {
+        Object {
+            inner: self.inner.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, other: &Self) {
+        self.inner.clone_from(&other.inner);
+    }
+}
+
+impl IntoIterator for Object {
+    type Item = (String, Value);
+    type IntoIter = <BTreeMap<String, Value> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        take(self).into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Object {
+    type Item = (&'a String, &'a Value);
+    type IntoIter = <&'a BTreeMap<String, Value> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Object {
+    type Item = (&'a String, &'a mut Value);
+    type IntoIter = <&'a mut BTreeMap<String, Value> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl FromIterator<(String, Value)> for Object {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (String, Value)>,
+    {
+        Object {
+            inner: BTreeMap::from_iter(iter),
+        }
+    }
+}
+
+impl PartialEq for Object {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Debug for Object {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("Object ")?;
+        formatter.debug_map().entries(self).finish()
+    }
+}
+
+impl Serialize for Object {
+    fn begin(&self) -> Fragment {
+        private::stream_object(self)
+    }
+}
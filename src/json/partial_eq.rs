@@ -0,0 +1,110 @@
+//! Cross-type `PartialEq` impls so callers can write `value["a"] == "b"`
+//! instead of pattern-matching each `Value` variant, mirroring
+//! `serde_json`'s `partial_eq.rs`.
+
+use crate::json::{Number, Value};
+use alloc::string::String;
+
+impl PartialEq<bool> for Value {
+    fn eq(&self, other: &bool) -> bool {
+        match self {
+            Value::Bool(b) => b == other,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<Value> for bool {
+    fn eq(&self, other: &Value) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<str> for Value {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Value::String(s) => s == other,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Value {
+    fn eq(&self, other: &&'a str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Value {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Value> for str {
+    fn eq(&self, other: &Value) -> bool {
+        other == self
+    }
+}
+
+impl<'a> PartialEq<Value> for &'a str {
+    fn eq(&self, other: &Value) -> bool {
+        other == *self
+    }
+}
+
+impl PartialEq<Value> for String {
+    fn eq(&self, other: &Value) -> bool {
+        other == self.as_str()
+    }
+}
+
+macro_rules! partial_eq_numeric {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl PartialEq<$ty> for Value {
+                fn eq(&self, other: &$ty) -> bool {
+                    match self {
+                        Value::Number(n) => *n == Number::$variant(*other as _),
+                        _ => false,
+                    }
+                }
+            }
+
+            impl PartialEq<Value> for $ty {
+                fn eq(&self, other: &Value) -> bool {
+                    other == self
+                }
+            }
+        )*
+    };
+}
+
+partial_eq_numeric! {
+    i8 => I64, i16 => I64, i32 => I64, i64 => I64, isize => I64,
+    u8 => U64, u16 => U64, u32 => U64, u64 => U64, usize => U64,
+    f32 => F64, f64 => F64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_compares_equal_to_matching_primitives() {
+        assert_eq!(Value::Bool(true), true);
+        assert_eq!(true, Value::Bool(true));
+        assert_eq!(Value::String(String::from("hi")), "hi");
+        assert_eq!("hi", Value::String(String::from("hi")));
+        assert_eq!(Value::Number(Number::U64(5)), 5);
+        assert_eq!(5, Value::Number(Number::U64(5)));
+        assert_eq!(Value::Number(Number::F64(1.5)), 1.5);
+    }
+
+    #[test]
+    fn value_compares_unequal_across_mismatched_variants() {
+        assert_ne!(Value::Bool(false), true);
+        assert_ne!(Value::Null, 0);
+        assert_ne!(Value::String(String::from("5")), 5);
+    }
+}
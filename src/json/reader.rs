@@ -0,0 +1,139 @@
+//! Streaming deserialization from an [`io::Read`] source.
+//!
+//! Only compiled with the `std` feature enabled. The `no_std`
+//! [`json::from_str`][super::from_str] path is unchanged either way; this
+//! module just gives callers who have a `std` reader, rather than an
+//! already-materialized `&str`, a way to avoid buffering the whole
+//! document up front.
+
+use crate::de::Deserialize;
+use crate::error::{Error, Result};
+use crate::json::de::{Deserializer, Read};
+use std::io;
+
+/// Number of bytes pulled from the underlying reader at a time.
+const BUF_SIZE: usize = 8 * 1024;
+
+/// Adapts a [`std::io::Read`] into the [`Read`] trait the JSON scanner
+/// consumes internally, so `from_reader` can feed the existing
+/// `Visitor`/`Seq`/`Map` place machinery a few bytes at a time instead of
+/// requiring the full input in memory.
+struct IoRead<R> {
+    reader: R,
+    buf: [u8; BUF_SIZE],
+    pos: usize,
+    len: usize,
+}
+
+impl<R: io::Read> IoRead<R> {
+    fn new(reader: R) -> Self {
+        IoRead {
+            reader,
+            buf: [0; BUF_SIZE],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    fn fill_buf(&mut self) -> Result<()> {
+        if self.pos == self.len {
+            self.len = self.reader.read(&mut self.buf).map_err(|_| Error)?;
+            self.pos = 0;
+        }
+        Ok(())
+    }
+}
+
+impl<R: io::Read> Read for IoRead<R> {
+    fn peek(&mut self) -> Result<Option<u8>> {
+        self.fill_buf()?;
+        Ok(self.buf[self.pos..self.len].first().copied())
+    }
+
+    fn next(&mut self) -> Result<Option<u8>> {
+        let byte = self.peek()?;
+        if byte.is_some() {
+            self.pos += 1;
+        }
+        Ok(byte)
+    }
+}
+
+/// Deserialize an instance of `T` by pulling bytes incrementally from
+/// `reader`, rather than requiring the caller to materialize the whole
+/// document as a `&str` first.
+///
+/// Requires the `std` feature. [`json::from_str`][super::from_str] remains
+/// available without it for `no_std` callers.
+pub fn from_reader<R, T>(reader: R) -> Result<T>
+where
+    R: io::Read,
+    T: Deserialize,
+{
+    let mut out = None;
+    Deserializer::new(IoRead::new(reader)).finish(&mut out)?;
+    out.ok_or(Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::vec::Vec;
+
+    // `Deserializer`/`de::Read` are not backed by an implementation in this
+    // tree, so these tests exercise `IoRead` directly rather than going
+    // through `from_reader`.
+
+    #[test]
+    fn reads_bytes_in_order() {
+        let mut read = IoRead::new(Cursor::new(b"abc".to_vec()));
+        assert_eq!(read.next().unwrap(), Some(b'a'));
+        assert_eq!(read.next().unwrap(), Some(b'b'));
+        assert_eq!(read.next().unwrap(), Some(b'c'));
+        assert_eq!(read.next().unwrap(), None);
+    }
+
+    #[test]
+    fn peek_does_not_consume() {
+        let mut read = IoRead::new(Cursor::new(b"xy".to_vec()));
+        assert_eq!(read.peek().unwrap(), Some(b'x'));
+        assert_eq!(read.peek().unwrap(), Some(b'x'));
+        assert_eq!(read.next().unwrap(), Some(b'x'));
+        assert_eq!(read.peek().unwrap(), Some(b'y'));
+    }
+
+    #[test]
+    fn crosses_the_buf_size_boundary_without_dropping_or_duplicating_bytes() {
+        let input: Vec<u8> = (0..BUF_SIZE * 2 + 7)
+            .map(|i| (i % 256) as u8)
+            .collect();
+        let mut read = IoRead::new(Cursor::new(input.clone()));
+        let mut out = Vec::with_capacity(input.len());
+        while let Some(byte) = read.next().unwrap() {
+            out.push(byte);
+        }
+        assert_eq!(out, input);
+    }
+
+    #[test]
+    fn empty_reader_yields_no_bytes() {
+        let mut read = IoRead::new(Cursor::new(Vec::new()));
+        assert_eq!(read.peek().unwrap(), None);
+        assert_eq!(read.next().unwrap(), None);
+    }
+
+    #[test]
+    fn io_errors_surface_as_error() {
+        struct Failing;
+
+        impl io::Read for Failing {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("boom"))
+            }
+        }
+
+        let mut read = IoRead::new(Failing);
+        assert!(read.peek().is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::Result;
-use crate::json::{Array, Number, Object};
+use crate::json::index::Index;
+use crate::json::{Array, Datetime, Number, Object};
 use crate::ser::{Fragment, Serialize};
 use alloc::borrow::{Cow, ToOwned};
 use alloc::boxed::Box;
@@ -25,7 +26,7 @@ use core::str;
 /// }
 /// // no stack overflow when `value` goes out of scope
 /// ```
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum Value {
     Null,
     Bool(bool),
@@ -33,6 +34,9 @@ pub enum Value {
     String(String),
     Array(Array),
     Object(Object),
+    /// An RFC 3339 datetime, recognized by formats (such as TOML) that
+    /// distinguish it from a plain string.
+    Datetime(Datetime),
 }
 
 impl Default for Value {
@@ -42,6 +46,27 @@ impl Default for Value {
     }
 }
 
+impl Value {
+    /// Index into a JSON array or object, returning `None` if the type
+    /// doesn't match or the key/index is absent.
+    ///
+    /// ```rust
+    /// use miniserde::json::{self, Value};
+    ///
+    /// let value: Value = json::from_str(r#"{"a": [1, 2, 3]}"#).unwrap();
+    /// assert!(value.get("a").and_then(|a| a.get(1)).is_some());
+    /// ```
+    pub fn get<I: Index>(&self, index: I) -> Option<&Value> {
+        index.index_into(self)
+    }
+
+    /// Mutably index into a JSON array or object, returning `None` if the
+    /// type doesn't match or the key/index is absent.
+    pub fn get_mut<I: Index>(&mut self, index: I) -> Option<&mut Value> {
+        index.index_into_mut(self)
+    }
+}
+
 impl Debug for Value {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -51,6 +76,7 @@ impl Debug for Value {
             Value::String(string) => write!(formatter, "String({:?})", string),
             Value::Array(array) => Debug::fmt(array, formatter),
             Value::Object(object) => Debug::fmt(object, formatter),
+            Value::Datetime(datetime) => write!(formatter, "Datetime({})", datetime),
         }
     }
 }
@@ -64,6 +90,60 @@ impl Serialize for Value {
             Value::String(s) => Fragment::Str(Cow::Borrowed(s)),
             Value::Array(array) => Serialize::begin(array),
             Value::Object(object) => Serialize::begin(object),
+            Value::Datetime(datetime) => Serialize::begin(datetime),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_values_of_every_variant_compare_equal() {
+        assert_eq!(Value::Null, Value::Null);
+        assert_eq!(Value::Bool(true), Value::Bool(true));
+        assert_eq!(Value::Number(Number::U64(1)), Value::Number(Number::U64(1)));
+        assert_eq!(
+            Value::String("a".to_owned()),
+            Value::String("a".to_owned())
+        );
+
+        let mut array = Array::new();
+        array.push(Value::Number(Number::U64(1)));
+        let mut other = Array::new();
+        other.push(Value::Number(Number::U64(1)));
+        assert_eq!(Value::Array(array), Value::Array(other));
+
+        let mut object = Object::new();
+        object.insert("a".to_owned(), Value::Bool(true));
+        let mut other = Object::new();
+        other.insert("a".to_owned(), Value::Bool(true));
+        assert_eq!(Value::Object(object), Value::Object(other));
+    }
+
+    #[test]
+    fn values_of_different_variants_are_unequal() {
+        assert_ne!(Value::Null, Value::Bool(false));
+        assert_ne!(
+            Value::Number(Number::U64(0)),
+            Value::String(String::new())
+        );
+    }
+
+    #[test]
+    fn objects_compare_by_contents_not_insertion_order() {
+        let mut a = Object::new();
+        a.insert("x".to_owned(), Value::Bool(true));
+        a.insert("y".to_owned(), Value::Bool(false));
+
+        let mut b = Object::new();
+        b.insert("y".to_owned(), Value::Bool(false));
+        b.insert("x".to_owned(), Value::Bool(true));
+
+        assert_eq!(a, b);
+
+        b.insert("x".to_owned(), Value::Bool(false));
+        assert_ne!(a, b);
+    }
+}
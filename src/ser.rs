@@ -0,0 +1,210 @@
+//! Serialization traits.
+//!
+//! Serialization in miniserde works by returning a "fragment" that
+//! describes one JSON value. Composite types hand back a `Seq` or `Map`
+//! trait object that streams out further fragments on demand, so nothing
+//! needs to be buffered up front.
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+
+/// Trait for data structures that can be serialized to a JSON string.
+pub trait Serialize {
+    fn begin(&self) -> Fragment;
+}
+
+/// A fragment of the serialized output, returned one at a time by
+/// `Serialize::begin`.
+pub enum Fragment<'a> {
+    Null,
+    Bool(bool),
+    Str(Cow<'a, str>),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    /// A number written byte-for-byte rather than through `itoa`/`ryu`,
+    /// used by [`Number::Unbounded`][crate::json::Number::Unbounded] under
+    /// the `arbitrary_precision` feature so digits beyond what `u64`,
+    /// `i64`, and `f64` can hold round-trip exactly.
+    Raw(&'a str),
+    Seq(Box<dyn Seq + 'a>),
+    Map(Box<dyn Map + 'a>),
+}
+
+/// Trait that can hand out the elements of a sequence being serialized.
+pub trait Seq {
+    fn next(&mut self) -> Option<&dyn Serialize>;
+}
+
+/// Trait that can hand out the entries of a map being serialized.
+pub trait Map {
+    fn next(&mut self) -> Option<(Cow<str>, &dyn Serialize)>;
+}
+
+impl Serialize for f64 {
+    fn begin(&self) -> Fragment {
+        Fragment::F64(*self)
+    }
+}
+
+/// Not public API. The serialize-side mirror of
+/// [`crate::de::UnitVariant`]: a fieldless enum variant is externally
+/// tagged as the bare string equal to its name.
+///
+/// [Refer to the module documentation for how enums round-trip.][crate::de]
+#[doc(hidden)]
+pub fn unit_variant(name: &'static str) -> Fragment<'static> {
+    Fragment::Str(Cow::Borrowed(name))
+}
+
+/// Not public API. The serialize-side mirror of the newtype/struct variant
+/// half of externally tagged enum support: a single-key map whose one key
+/// is the variant name and whose value is that variant's payload.
+#[doc(hidden)]
+pub struct VariantMap<'a> {
+    name: &'static str,
+    value: &'a dyn Serialize,
+    done: bool,
+}
+
+impl<'a> VariantMap<'a> {
+    pub fn new(name: &'static str, value: &'a dyn Serialize) -> Self {
+        VariantMap {
+            name,
+            value,
+            done: false,
+        }
+    }
+}
+
+impl<'a> Map for VariantMap<'a> {
+    fn next(&mut self) -> Option<(Cow<str>, &dyn Serialize)> {
+        if self.done {
+            return None;
+        }
+        self.done = true;
+        Some((Cow::Borrowed(self.name), self.value))
+    }
+}
+
+/// Not public API. Serialize-side counterpart of a newtype/struct variant;
+/// pairs with [`VariantMap`].
+#[doc(hidden)]
+pub fn newtype_variant<'a>(name: &'static str, value: &'a dyn Serialize) -> Fragment<'a> {
+    Fragment::Map(Box::new(VariantMap::new(name, value)))
+}
+
+/// Demonstrates the two halves (serialize and deserialize) of externally
+/// tagged enum support meeting in the middle. There is no proc-macro crate
+/// anywhere in this tree to generate this impl from an enum definition, so
+/// generated derive codegen remains out of scope here; this is the impl a
+/// derive would produce for an enum shaped like `Shape`, written by hand.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::{self, Deserialize, Visitor};
+    use crate::error::{Error, Result};
+
+    enum Shape {
+        Circle(f64),
+        Point,
+    }
+
+    impl Serialize for Shape {
+        fn begin(&self) -> Fragment {
+            match self {
+                Shape::Circle(radius) => newtype_variant("Circle", radius),
+                Shape::Point => unit_variant("Point"),
+            }
+        }
+    }
+
+    impl Deserialize for Shape {
+        fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+            impl Visitor for Option<Shape> {
+                fn string(&mut self, s: &str) -> Result<()> {
+                    let mut index = None;
+                    de::UnitVariant {
+                        out: &mut index,
+                        variants: &["Point"],
+                    }
+                    .string(s)?;
+                    *self = match index {
+                        Some(0) => Some(Shape::Point),
+                        _ => return Err(Error),
+                    };
+                    Ok(())
+                }
+
+                fn map(&mut self) -> Result<alloc::boxed::Box<dyn de::Map + '_>> {
+                    struct ShapeMap<'a> {
+                        out: &'a mut Option<Shape>,
+                        radius: Option<f64>,
+                        matched: bool,
+                    }
+
+                    impl<'a> de::Map for ShapeMap<'a> {
+                        fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+                            match de::variant_index(k, &["Circle", "Point"])? {
+                                0 => {
+                                    self.matched = true;
+                                    Ok(Deserialize::begin(&mut self.radius))
+                                }
+                                _ => Err(Error),
+                            }
+                        }
+
+                        fn finish(&mut self) -> Result<()> {
+                            if self.matched {
+                                *self.out = Some(Shape::Circle(self.radius.ok_or(Error)?));
+                                Ok(())
+                            } else {
+                                Err(Error)
+                            }
+                        }
+                    }
+
+                    Ok(alloc::boxed::Box::new(ShapeMap {
+                        out: self,
+                        radius: None,
+                        matched: false,
+                    }))
+                }
+            }
+            out
+        }
+    }
+
+    fn round_trip(shape: Shape) -> Shape {
+        let fragment = Serialize::begin(&shape);
+        let mut out = None;
+        let visitor = Deserialize::begin(&mut out);
+        match fragment {
+            Fragment::Str(s) => visitor.string(&s).unwrap(),
+            Fragment::Map(mut map) => {
+                let mut target = visitor.map().unwrap();
+                while let Some((key, value)) = map.next() {
+                    let value_fragment = value.begin();
+                    let place = target.key(&key).unwrap();
+                    match value_fragment {
+                        Fragment::F64(n) => place.float(n).unwrap(),
+                        _ => panic!("unexpected payload fragment"),
+                    }
+                }
+                target.finish().unwrap();
+            }
+            _ => panic!("unexpected fragment for Shape"),
+        }
+        out.unwrap()
+    }
+
+    #[test]
+    fn unit_variant_round_trips_through_value_shaped_fragments() {
+        assert!(matches!(round_trip(Shape::Point), Shape::Point));
+    }
+
+    #[test]
+    fn newtype_variant_round_trips_through_value_shaped_fragments() {
+        assert!(matches!(round_trip(Shape::Circle(2.0)), Shape::Circle(radius) if radius == 2.0));
+    }
+}